@@ -1,6 +1,6 @@
 use crate::dma::mux::DmaMuxResources;
 use crate::dma::traits::TargetAddress;
-use crate::dma::MemoryToPeripheral;
+use crate::dma::{MemoryToPeripheral, PeripheralToMemory};
 use crate::gpio::{gpioa::*, gpiob::*, gpioc::*, gpiof::*, Alternate, AF5, AF6};
 #[cfg(any(
     feature = "stm32g471",
@@ -22,7 +22,10 @@ use crate::stm32::SPI4;
 use crate::stm32::{RCC, SPI1, SPI2, SPI3};
 use crate::time::Hertz;
 use core::cell::UnsafeCell;
+use core::future::poll_fn;
+use core::future::Future;
 use core::ptr;
+use core::task::Poll;
 
 pub use hal::spi::{Mode, Phase, Polarity, MODE_0, MODE_1, MODE_2, MODE_3};
 
@@ -63,6 +66,10 @@ pub trait PinMiso<SPI> {}
 
 pub trait PinMosi<SPI> {}
 
+/// A pin usable as the peripheral's hardware-managed `NSS` signal, for use
+/// with [`NssMode::Hardware`].
+pub trait PinNss<SPI> {}
+
 impl<SPI, SCK, MISO, MOSI> Pins<SPI> for (SCK, MISO, MOSI)
 where
     SCK: PinSck<SPI>,
@@ -75,6 +82,7 @@ where
 pub struct Spi<SPI, PINS> {
     spi: SPI,
     pins: PINS,
+    bus_freq: Hertz,
 }
 
 pub trait SpiExt<SPI>: Sized {
@@ -84,15 +92,209 @@ pub trait SpiExt<SPI>: Sized {
         T: Into<Hertz>;
 }
 
-pub trait FrameSize: Copy + Default {
-    const DFF: bool;
+/// Order in which bits are shifted out of/into the data register
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    MsbFirst,
+    LsbFirst,
+}
+
+/// SPI configuration, applicable to an already constructed [`Spi`] via
+/// [`Spi::apply_config`] or [`SetConfig`].
+///
+/// This allows switching mode, speed, or bit order at runtime, e.g. to talk
+/// to several devices with different requirements on a shared bus.
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    pub mode: Mode,
+    pub frequency: Hertz,
+    pub bit_order: BitOrder,
+    /// CRC polynomial to program into `CRCPR`, or `None` to leave the
+    /// hardware CRC engine disabled.
+    pub crc_polynomial: Option<u16>,
+}
+
+impl Config {
+    pub fn new(mode: Mode, frequency: Hertz) -> Self {
+        Self {
+            mode,
+            frequency,
+            bit_order: BitOrder::MsbFirst,
+            crc_polynomial: None,
+        }
+    }
+
+    pub fn bit_order(mut self, bit_order: BitOrder) -> Self {
+        self.bit_order = bit_order;
+        self
+    }
+
+    /// Enable the hardware CRC engine with the given polynomial.
+    pub fn crc_polynomial(mut self, polynomial: u16) -> Self {
+        self.crc_polynomial = Some(polynomial);
+        self
+    }
+}
+
+/// Types whose runtime configuration can be changed after construction.
+pub trait SetConfig {
+    type Config;
+    type Error;
+
+    fn set_config(&mut self, config: &Self::Config) -> Result<(), Self::Error>;
+}
+
+/// How the `NSS` (chip select) signal is managed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NssMode {
+    /// NSS is left entirely to the user's GPIO code (the default).
+    Software,
+    /// The peripheral drives its dedicated hardware NSS pin, automatically
+    /// asserting/deasserting it around transfers.
+    Hardware,
+}
+
+/// Per-transfer hardware behaviour, applied via [`Spi::apply_transfer_config`].
+///
+/// Unlike [`Config`] this doesn't touch mode/speed/bit order, just how `NSS`
+/// is driven, so single-slave setups can opt into glitch-free hardware chip
+/// select without rebuilding the `Spi`.
+#[derive(Debug, Clone, Copy)]
+pub struct TransferConfig {
+    pub nss: NssMode,
+}
+
+impl TransferConfig {
+    pub fn new(nss: NssMode) -> Self {
+        Self { nss }
+    }
+}
+
+fn compute_br(bus_freq: u32, spi_freq: u32) -> u8 {
+    match bus_freq / spi_freq {
+        0 => unreachable!(),
+        1..=2 => 0b000,
+        3..=5 => 0b001,
+        6..=11 => 0b010,
+        12..=23 => 0b011,
+        24..=47 => 0b100,
+        48..=95 => 0b101,
+        96..=191 => 0b110,
+        _ => 0b111,
+    }
+}
+
+/// A SPI peripheral configured to operate as a slave device on a shared bus,
+/// driven by an external master's clock and hardware NSS rather than
+/// generating its own. `NSS` must be a genuine [`PinNss`] pin: a slave has
+/// no way to tell its frames apart without the master's hardware select
+/// line actually wired up.
+#[derive(Debug)]
+pub struct SpiSlave<SPI, PINS, NSS> {
+    spi: SPI,
+    pins: PINS,
+    nss: NSS,
+}
+
+pub trait SpiSlaveExt<SPI>: Sized {
+    fn spi_slave<PINS, NSS>(
+        self,
+        pins: PINS,
+        nss: NSS,
+        mode: Mode,
+        rcc: &mut Rcc,
+    ) -> SpiSlave<SPI, PINS, NSS>
+    where
+        PINS: Pins<SPI>,
+        NSS: PinNss<SPI>;
+}
+
+pub trait FrameSize: Copy + Default + 'static {
+    /// Value for the 4-bit `CR2.DS` data size field.
+    const DS: u8;
+    /// Value for `CR2.FRXTH`: set for an 8-bit RX FIFO threshold, clear for
+    /// a 16-bit (half-word) threshold, so `RXNE` fires at the right fill
+    /// level for this word size.
+    const FRXTH: bool;
 }
 
 impl FrameSize for u8 {
-    const DFF: bool = false;
+    const DS: u8 = 0b0111;
+    const FRXTH: bool = true;
 }
 impl FrameSize for u16 {
-    const DFF: bool = true;
+    const DS: u8 = 0b1111;
+    const FRXTH: bool = false;
+}
+
+/// A DMA channel wired to drive an SPI peripheral's transmit (MOSI) side,
+/// completing once the channel itself reports the transfer finished (e.g.
+/// via its transfer-complete interrupt) rather than on a fixed schedule.
+pub trait SpiTxDmaChannel {
+    /// Move `buf` out to the peripheral register at `address`.
+    async fn transfer<W: FrameSize>(&mut self, address: u32, buf: &[W]);
+}
+
+/// A DMA channel wired to drive an SPI peripheral's receive (MISO) side,
+/// completing once the channel itself reports the transfer finished.
+pub trait SpiRxDmaChannel {
+    /// Fill `buf` from the peripheral register at `address`.
+    async fn transfer<W: FrameSize>(&mut self, address: u32, buf: &mut [W]);
+}
+
+/// Run two futures to completion without pulling in an executor-agnostic
+/// `join` crate: each is polled in turn, and the combined future only ever
+/// returns `Pending` when a child does, so wake-ups stay tied to whatever
+/// each child actually registered its waker against (here, DMA
+/// transfer-complete) rather than an unconditional self-wake.
+///
+/// `f1` is polled (and so armed) before `f2` on every call, including the
+/// first — callers driving a full-duplex SPI transfer should pass the RX
+/// side as `f1` so the receive channel is live before TX starts toggling
+/// the clock, instead of risking the first word being lost to overrun.
+async fn join_transfers<F1, F2>(f1: F1, f2: F2)
+where
+    F1: core::future::Future<Output = ()>,
+    F2: core::future::Future<Output = ()>,
+{
+    let mut f1 = core::pin::pin!(f1);
+    let mut f2 = core::pin::pin!(f2);
+    let mut f1_done = false;
+    let mut f2_done = false;
+    poll_fn(|cx| {
+        if !f1_done && f1.as_mut().poll(cx).is_ready() {
+            f1_done = true;
+        }
+        if !f2_done && f2.as_mut().poll(cx).is_ready() {
+            f2_done = true;
+        }
+        if f1_done && f2_done {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+/// An `Spi` paired with the DMA channels driving its MOSI/MISO FIFOs, so
+/// `embedded-hal-async`'s [`SpiBus`](embedded_hal_async::spi::SpiBus) can
+/// hand words to real hardware DMA instead of polling the FIFO from the
+/// CPU.
+///
+/// The async `SpiBus` lives here rather than directly on `Spi`: driving
+/// real DMA needs somewhere to own the TX/RX channel handles, and `Spi`
+/// itself has no such slot, so `with_dma` hands both off to this wrapper.
+pub struct SpiDma<SPI, PINS, TXCH, RXCH> {
+    spi: Spi<SPI, PINS>,
+    tx_channel: TXCH,
+    rx_channel: RXCH,
+}
+
+impl<SPI, PINS, TXCH, RXCH> SpiDma<SPI, PINS, TXCH, RXCH> {
+    pub fn release(self) -> (Spi<SPI, PINS>, TXCH, RXCH) {
+        (self.spi, self.tx_channel, self.rx_channel)
+    }
 }
 
 macro_rules! spi {
@@ -100,7 +302,9 @@ macro_rules! spi {
         sck: [ $($( #[ $pmetasck:meta ] )* $SCK:ty,)+ ],
         miso: [ $($( #[ $pmetamiso:meta ] )* $MISO:ty,)+ ],
         mosi: [ $($( #[ $pmetamosi:meta ] )* $MOSI:ty,)+ ],
-        $mux:expr,
+        nss: [ $($( #[ $pmetanss:meta ] )* $NSS:ty,)+ ],
+        $mux_tx:expr,
+        $mux_rx:expr,
     ) => {
         impl PinSck<$SPIX> for NoSck {}
 
@@ -108,6 +312,11 @@ macro_rules! spi {
 
         impl PinMosi<$SPIX> for NoMosi {}
 
+        // No blanket `PinNss` impl for a filler type here: unlike
+        // SCK/MISO/MOSI, NSS is optional at the protocol level (software
+        // chip select is the default), so there's nothing to fill in for
+        // — callers that don't need hardware NSS simply never ask for a
+        // `PinNss` bound.
         $(
             $( #[ $pmetasck ] )*
             impl PinSck<$SPIX> for $SCK {}
@@ -120,6 +329,10 @@ macro_rules! spi {
             $( #[ $pmetamosi ] )*
             impl PinMosi<$SPIX> for $MOSI {}
         )*
+        $(
+            $( #[ $pmetanss ] )*
+            impl PinNss<$SPIX> for $NSS {}
+        )*
 
         impl<PINS: Pins<$SPIX>> Spi<$SPIX, PINS> {
             pub fn $spiX<T>(
@@ -144,17 +357,7 @@ macro_rules! spi {
 
                 let spi_freq = speed.into().raw();
                 let bus_freq = <$SPIX as RccBus>::Bus::get_frequency(&rcc.clocks).raw();
-                let br = match bus_freq / spi_freq {
-                    0 => unreachable!(),
-                    1..=2 => 0b000,
-                    3..=5 => 0b001,
-                    6..=11 => 0b010,
-                    12..=23 => 0b011,
-                    24..=47 => 0b100,
-                    48..=95 => 0b101,
-                    96..=191 => 0b110,
-                    _ => 0b111,
-                };
+                let br = compute_br(bus_freq, spi_freq);
 
                 spi.cr2.write(|w| unsafe {
                     w.frxth().set_bit().ds().bits(0b111).ssoe().clear_bit()
@@ -187,18 +390,148 @@ macro_rules! spi {
                         .set_bit()
                 });
 
-                Spi { spi, pins }
+                Spi {
+                    spi,
+                    pins,
+                    bus_freq: Hertz::from_raw(bus_freq),
+                }
             }
 
             pub fn release(self) -> ($SPIX, PINS) {
                 (self.spi, self.pins)
             }
 
+            /// Re-programs mode, speed and bit order without rebuilding the
+            /// `Spi`, so it can be reused across devices with different
+            /// requirements on a shared bus. Leaves whatever NSS mode
+            /// [`Spi::apply_transfer_config`] last set, and whatever frame
+            /// size a prior `SpiBus::<W>` call last set, untouched rather
+            /// than silently resetting either.
+            pub fn apply_config(&mut self, config: &Config) {
+                let spi_freq = config.frequency.raw();
+                let br = compute_br(self.bus_freq.raw(), spi_freq);
+                let hw_nss = self.spi.cr2.read().ssoe().bit_is_set();
+
+                // disable the peripheral before touching CR1/CR2
+                self.spi.cr1.modify(|_, w| w.spe().clear_bit());
+
+                // leave FRXTH/DS (frame size) as `SpiBus::<W>` last set them
+                self.spi.cr2.modify(|r, w| unsafe {
+                    w.frxth()
+                        .bit(r.frxth().bit_is_set())
+                        .ds()
+                        .bits(r.ds().bits())
+                        .ssoe()
+                        .bit(hw_nss)
+                });
+
+                if let Some(polynomial) = config.crc_polynomial {
+                    self.spi.crcpr.write(|w| unsafe { w.crcpoly().bits(polynomial) });
+                }
+
+                self.spi.cr1.write(|w| unsafe {
+                    w.cpha()
+                        .bit(config.mode.phase == Phase::CaptureOnSecondTransition)
+                        .cpol()
+                        .bit(config.mode.polarity == Polarity::IdleHigh)
+                        .mstr()
+                        .set_bit()
+                        .br()
+                        .bits(br)
+                        .lsbfirst()
+                        .bit(config.bit_order == BitOrder::LsbFirst)
+                        .ssm()
+                        .bit(!hw_nss)
+                        .ssi()
+                        .set_bit()
+                        .rxonly()
+                        .clear_bit()
+                        .dff()
+                        .clear_bit()
+                        .bidimode()
+                        .clear_bit()
+                        .crcen()
+                        .bit(config.crc_polynomial.is_some())
+                        .spe()
+                        .set_bit()
+                });
+            }
+
+            /// Set `CR1.CRCNEXT` so the next byte written to `DR` is the
+            /// computed CRC rather than data, per the reference manual's
+            /// procedure for appending a CRC to a transmitted frame.
+            pub fn send_crc(&mut self) {
+                self.spi.cr1.modify(|_, w| w.crcnext().set_bit());
+            }
+
+            /// Read the CRC value computed over the received data (`RXCRCR`).
+            pub fn rx_crc(&self) -> u16 {
+                self.spi.rxcrcr.read().rxcrc().bits()
+            }
+
+            /// Read the CRC value computed over the transmitted data (`TXCRCR`).
+            pub fn tx_crc(&self) -> u16 {
+                self.spi.txcrcr.read().txcrc().bits()
+            }
+
+            /// Switch between software- and hardware-managed `NSS`. Takes
+            /// the actual pin wired to the peripheral's dedicated NSS line
+            /// (see [`PinNss`]) so [`NssMode::Hardware`] can't be switched
+            /// on without a genuine hardware-capable pin proved at the
+            /// type level — `nss` is otherwise unused, its only purpose is
+            /// this proof of possession.
+            pub fn apply_transfer_config<NSS: PinNss<$SPIX>>(
+                &mut self,
+                _nss: &NSS,
+                config: &TransferConfig,
+            ) {
+                self.spi.cr1.modify(|_, w| w.spe().clear_bit());
+                match config.nss {
+                    NssMode::Software => {
+                        self.spi
+                            .cr1
+                            .modify(|_, w| w.ssm().set_bit().ssi().set_bit());
+                        self.spi.cr2.modify(|_, w| w.ssoe().clear_bit());
+                    }
+                    NssMode::Hardware => {
+                        self.spi.cr1.modify(|_, w| w.ssm().clear_bit());
+                        self.spi.cr2.modify(|_, w| w.ssoe().set_bit());
+                    }
+                }
+                self.spi.cr1.modify(|_, w| w.spe().set_bit());
+            }
+
             pub fn enable_tx_dma(self) -> Spi<$SPIX, PINS> {
                 self.spi.cr2.modify(|_, w| w.txdmaen().set_bit());
                 Spi {
                     spi: self.spi,
                     pins: self.pins,
+                    bus_freq: self.bus_freq,
+                }
+            }
+
+            pub fn enable_rx_dma(self) -> Spi<$SPIX, PINS> {
+                self.spi.cr2.modify(|_, w| w.rxdmaen().set_bit());
+                Spi {
+                    spi: self.spi,
+                    pins: self.pins,
+                    bus_freq: self.bus_freq,
+                }
+            }
+
+            /// Pair this `Spi` with the DMA channels that will drive its
+            /// MOSI/MISO FIFOs, enabling `TXDMAEN`/`RXDMAEN` so
+            /// `embedded-hal-async`'s `SpiBus` hands words to hardware DMA
+            /// instead of polling the FIFO from the CPU.
+            pub fn with_dma<TXCH, RXCH>(
+                self,
+                tx_channel: TXCH,
+                rx_channel: RXCH,
+            ) -> SpiDma<$SPIX, PINS, TXCH, RXCH> {
+                SpiDma {
+                    spi: self.enable_tx_dma().enable_rx_dma(),
+                    tx_channel,
+                    rx_channel,
                 }
             }
         }
@@ -236,7 +569,7 @@ macro_rules! spi {
                 })
             }
             #[inline]
-            fn nb_read_no_err(&mut self) -> nb::Result<u8, ()> {
+            fn nb_read_no_err<W: FrameSize>(&mut self) -> nb::Result<W, ()> {
                 if self.spi.sr.read().rxne().bit_is_set() {
                     Ok(self.read_unchecked())
                 } else {
@@ -267,13 +600,25 @@ macro_rules! spi {
                     .cr1
                     .modify(|_, w| w.bidimode().clear_bit().bidioe().clear_bit());
             }
-            fn fifo_cap(&self) -> u8 {
-                match self.spi.sr.read().ftlvl().bits() {
+            fn fifo_cap<W: FrameSize>(&self) -> u8 {
+                let free_bytes = match self.spi.sr.read().ftlvl().bits() {
                     0 => 4,
                     1 => 3,
                     2 => 2,
                     _ => 0,
-                }
+                };
+                free_bytes / core::mem::size_of::<W>() as u8
+            }
+
+            /// Reprogram `CR2.DS`/`CR2.FRXTH` for the word size `W`, so the
+            /// RX FIFO threshold and frame width match the type the caller
+            /// is about to read/write through `SpiBus<W>`.
+            fn set_frame_size<W: FrameSize>(&mut self) {
+                self.spi.cr1.modify(|_, w| w.spe().clear_bit());
+                self.spi
+                    .cr2
+                    .modify(|_, w| unsafe { w.frxth().bit(W::FRXTH).ds().bits(W::DS) });
+                self.spi.cr1.modify(|_, w| w.spe().set_bit());
             }
         }
 
@@ -287,33 +632,231 @@ macro_rules! spi {
                 }
         }
 
+        impl<PINS> SetConfig for Spi<$SPIX, PINS> {
+            type Config = Config;
+            type Error = core::convert::Infallible;
+
+            fn set_config(&mut self, config: &Self::Config) -> Result<(), Self::Error> {
+                self.apply_config(config);
+                Ok(())
+            }
+        }
+
+        impl<PINS: Pins<$SPIX>, NSS: PinNss<$SPIX>> SpiSlave<$SPIX, PINS, NSS> {
+            pub fn new(spi: $SPIX, pins: PINS, nss: NSS, mode: Mode, rcc: &mut Rcc) -> Self {
+                // Enable and reset SPI
+                unsafe {
+                    let rcc_ptr = &(*RCC::ptr());
+                    $SPIX::enable(rcc_ptr);
+                    $SPIX::reset(rcc_ptr);
+                }
+
+                spi.cr2
+                    .write(|w| unsafe { w.frxth().set_bit().ds().bits(0b111).ssoe().clear_bit() });
+
+                spi.cr1.write(|w| {
+                    w.cpha()
+                        .bit(mode.phase == Phase::CaptureOnSecondTransition)
+                        .cpol()
+                        .bit(mode.polarity == Polarity::IdleHigh)
+                        // slave mode: the external master drives SCK and NSS
+                        .mstr()
+                        .clear_bit()
+                        .lsbfirst()
+                        .clear_bit()
+                        // use the hardware NSS pin rather than software slave select
+                        .ssm()
+                        .clear_bit()
+                        .rxonly()
+                        .clear_bit()
+                        .dff()
+                        .clear_bit()
+                        .bidimode()
+                        .clear_bit()
+                        .spe()
+                        .set_bit()
+                });
+
+                SpiSlave { spi, pins, nss }
+            }
+
+            pub fn release(self) -> ($SPIX, PINS, NSS) {
+                (self.spi, self.pins, self.nss)
+            }
+        }
+
+        impl<PINS, NSS> SpiSlave<$SPIX, PINS, NSS> {
+            #[inline]
+            fn nb_read<W: FrameSize>(&mut self) -> nb::Result<W, Error> {
+                let sr = self.spi.sr.read();
+                Err(if sr.ovr().bit_is_set() {
+                    nb::Error::Other(Error::Overrun)
+                } else if sr.modf().bit_is_set() {
+                    nb::Error::Other(Error::ModeFault)
+                } else if sr.crcerr().bit_is_set() {
+                    nb::Error::Other(Error::Crc)
+                } else if sr.rxne().bit_is_set() {
+                    return Ok(self.read_unchecked());
+                } else {
+                    nb::Error::WouldBlock
+                })
+            }
+            #[inline]
+            fn nb_write<W: FrameSize>(&mut self, word: W) -> nb::Result<(), Error> {
+                let sr = self.spi.sr.read();
+                Err(if sr.ovr().bit_is_set() {
+                    nb::Error::Other(Error::Overrun)
+                } else if sr.modf().bit_is_set() {
+                    nb::Error::Other(Error::ModeFault)
+                } else if sr.crcerr().bit_is_set() {
+                    nb::Error::Other(Error::Crc)
+                } else if sr.txe().bit_is_set() {
+                    self.write_unchecked(word);
+                    return Ok(());
+                } else {
+                    nb::Error::WouldBlock
+                })
+            }
+            #[inline]
+            fn read_unchecked<W: FrameSize>(&mut self) -> W {
+                // NOTE(read_volatile) read exactly one `W`-sized word (the svd2rust
+                // API only allows reading a half-word)
+                unsafe { ptr::read_volatile(&self.spi.dr as *const _ as *const W) }
+            }
+            #[inline]
+            fn write_unchecked<W: FrameSize>(&mut self, word: W) {
+                let dr = &self.spi.dr as *const _ as *const UnsafeCell<W>;
+                // NOTE(write_volatile) see note above
+                unsafe { ptr::write_volatile(UnsafeCell::raw_get(dr), word) };
+            }
+            fn set_frame_size<W: FrameSize>(&mut self) {
+                self.spi.cr1.modify(|_, w| w.spe().clear_bit());
+                self.spi
+                    .cr2
+                    .modify(|_, w| unsafe { w.frxth().bit(W::FRXTH).ds().bits(W::DS) });
+                self.spi.cr1.modify(|_, w| w.spe().set_bit());
+            }
+        }
+
+        impl SpiSlaveExt<$SPIX> for $SPIX {
+            fn spi_slave<PINS, NSS>(
+                self,
+                pins: PINS,
+                nss: NSS,
+                mode: Mode,
+                rcc: &mut Rcc,
+            ) -> SpiSlave<$SPIX, PINS, NSS>
+            where
+                PINS: Pins<$SPIX>,
+                NSS: PinNss<$SPIX>,
+            {
+                SpiSlave::new(self, pins, nss, mode, rcc)
+            }
+        }
+
+        impl<PINS, NSS> embedded_hal_one::spi::ErrorType for SpiSlave<$SPIX, PINS, NSS> {
+            type Error = Error;
+        }
+
+        impl<PINS, NSS, W: FrameSize> embedded_hal_one::spi::SpiBus<W> for SpiSlave<$SPIX, PINS, NSS> {
+            fn read(&mut self, words: &mut [W]) -> Result<(), Self::Error> {
+                self.set_frame_size::<W>();
+                for r in words.iter_mut() {
+                    *r = nb::block!(self.nb_read())?;
+                }
+                Ok(())
+            }
+
+            fn write(&mut self, words: &[W]) -> Result<(), Self::Error> {
+                self.set_frame_size::<W>();
+                // A slave shifts a word into the RX FIFO for every word the
+                // master clocks out of it, whether or not the caller wants
+                // it back, so drain and discard each one here — otherwise
+                // the 4-deep FIFO fills mid-write and `nb_write` starts
+                // reporting spurious `Error::Overrun`.
+                for w in words {
+                    nb::block!(self.nb_write(*w))?;
+                    nb::block!(self.nb_read())?;
+                }
+                Ok(())
+            }
+
+            fn transfer(&mut self, read: &mut [W], write: &[W]) -> Result<(), Self::Error> {
+                self.set_frame_size::<W>();
+                let common_len = core::cmp::min(read.len(), write.len());
+                for (r, w) in read.iter_mut().zip(write.iter()).take(common_len) {
+                    nb::block!(self.nb_write(*w))?;
+                    *r = nb::block!(self.nb_read())?;
+                }
+                if read.len() > common_len {
+                    self.read(&mut read[common_len..])
+                } else {
+                    self.write(&write[common_len..])
+                }
+            }
+
+            fn transfer_in_place(&mut self, words: &mut [W]) -> Result<(), Self::Error> {
+                self.set_frame_size::<W>();
+                for w in words.iter_mut() {
+                    nb::block!(self.nb_write(*w))?;
+                    *w = nb::block!(self.nb_read())?;
+                }
+                Ok(())
+            }
+
+            fn flush(&mut self) -> Result<(), Self::Error> {
+                while self.spi.sr.read().ftlvl() != 0 {
+                    core::hint::spin_loop()
+                }
+                Ok(())
+            }
+        }
+
+        // `FullDuplex` stays `u8`-only: it has no equivalent of `SpiBus`'s
+        // call-site `set_frame_size::<W>()`, and reprogramming `CR2.DS` on
+        // every `read`/`send` would both be expensive and corrupt a
+        // `SpiBus` caller's in-flight frame width.
+        impl<PINS, NSS> hal::spi::FullDuplex<u8> for SpiSlave<$SPIX, PINS, NSS> {
+            type Error = Error;
+
+            fn read(&mut self) -> nb::Result<u8, Error> {
+                self.nb_read()
+            }
+
+            fn send(&mut self, word: u8) -> nb::Result<(), Error> {
+                self.nb_write(word)
+            }
+        }
+
         impl<PINS> embedded_hal_one::spi::ErrorType for Spi<$SPIX, PINS> {
             type Error = Error;
         }
 
-        impl<PINS> embedded_hal_one::spi::SpiBus for Spi<$SPIX, PINS> {
-            fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+        impl<PINS, W: FrameSize> embedded_hal_one::spi::SpiBus<W> for Spi<$SPIX, PINS> {
+            fn read(&mut self, words: &mut [W]) -> Result<(), Self::Error> {
                 if words.len() == 0 { return Ok(()) }
+                self.set_frame_size::<W>();
 
                 // prefill write fifo so that the clock doen't stop while fetch the read byte
-                let prefill = self.fifo_cap() as usize;
+                let prefill = self.fifo_cap::<W>() as usize;
                 for _ in 0..prefill {
-                    nb::block!(self.nb_write(0u8))?;
+                    nb::block!(self.nb_write(W::default()))?;
                 }
 
                 let len = words.len();
                 for r in words[..len-prefill].iter_mut() {
-                    // TODO: 16 bit frames, bidirectional pins
-                    nb::block!(self.nb_write(0u8))?;
+                    // TODO: bidirectional pins
+                    nb::block!(self.nb_write(W::default()))?;
                     // errors have been checked by the write above
-                    *r = unsafe { nb::block!(self.nb_read_no_err()).unwrap_unchecked() };
+                    *r = unsafe { nb::block!(self.nb_read_no_err::<W>()).unwrap_unchecked() };
                 }
                 Ok(for r in words[len-prefill..].iter_mut() {
                     *r = nb::block!(self.nb_read())?;
                 })
             }
 
-            fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+            fn write(&mut self, words: &[W]) -> Result<(), Self::Error> {
+                self.set_frame_size::<W>();
                 let catch = |spi: &mut Self| Ok(for w in words {
                         nb::block!(spi.nb_write(*w))?
                     });
@@ -324,14 +867,15 @@ macro_rules! spi {
                 res
             }
 
-            fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
+            fn transfer(&mut self, read: &mut [W], write: &[W]) -> Result<(), Self::Error> {
                 if read.len() == 0 {
                     return self.write(write)
                 } else if write.len() == 0 {
                     return self.read(read)
                 }
+                self.set_frame_size::<W>();
 
-                let prefill = self.fifo_cap();
+                let prefill = self.fifo_cap::<W>();
                 let mut write_iter = write.into_iter();
 
                 // same prefill as in read, this time with actual data
@@ -346,28 +890,29 @@ macro_rules! spi {
                 let zipped = read.iter_mut().zip(write_iter).take(common_len - prefilled);
                 for (r, w) in zipped {
                     nb::block!(self.nb_write(*w))?;
-                    *r = unsafe { nb::block!(self.nb_read_no_err()).unwrap_unchecked() };
+                    *r = unsafe { nb::block!(self.nb_read_no_err::<W>()).unwrap_unchecked() };
                 }
 
                 // read words left in the fifo
                 for r in read[common_len-prefilled..common_len].iter_mut() {
                     *r = nb::block!(self.nb_read())?
                 }
-                
+
                 if read.len() > common_len {
                     self.read(&mut read[common_len..])
                 } else {
                     self.write(&write[common_len..])
                 }
             }
-            fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+            fn transfer_in_place(&mut self, words: &mut [W]) -> Result<(), Self::Error> {
                 if words.len() == 0 { return Ok(()) }
+                self.set_frame_size::<W>();
 
                 let cells = core::cell::Cell::from_mut(words).as_slice_of_cells();
                 let mut write_iter = cells.into_iter();
                 let mut read_iter = cells.into_iter();
 
-                let prefill = self.fifo_cap();
+                let prefill = self.fifo_cap::<W>();
 
                 for w in write_iter.by_ref().take(prefill as usize) {
                     nb::block!(self.nb_write(w.get()))?;
@@ -375,7 +920,7 @@ macro_rules! spi {
 
                 for (r, w) in write_iter.zip(read_iter.by_ref()) {
                     nb::block!(self.nb_write(w.get()))?;
-                    r.set(unsafe { nb::block!(self.nb_read_no_err()).unwrap_unchecked() });
+                    r.set(unsafe { nb::block!(self.nb_read_no_err::<W>()).unwrap_unchecked() });
                 }
 
                 Ok(for r in read_iter {
@@ -385,7 +930,7 @@ macro_rules! spi {
             fn flush(&mut self) -> Result<(), Self::Error> {
                 let catch = |spi: &mut Self| {
                     // drain rx fifo
-                    while match spi.nb_read::<u8>() {
+                    while match spi.nb_read::<W>() {
                         Ok(_) => true,
                         Err(nb::Error::WouldBlock) => false,
                         Err(nb::Error::Other(e)) => { return Err(e) }
@@ -403,6 +948,10 @@ macro_rules! spi {
             }
         }
 
+        // `FullDuplex` stays `u8`-only: it has no equivalent of `SpiBus`'s
+        // call-site `set_frame_size::<W>()`, and reprogramming `CR2.DS` on
+        // every `read`/`send` would both be expensive and corrupt a
+        // `SpiBus` caller's in-flight frame width.
         impl<PINS> hal::spi::FullDuplex<u8> for Spi<$SPIX, PINS> {
             type Error = Error;
 
@@ -410,10 +959,124 @@ macro_rules! spi {
                 self.nb_read()
             }
 
-            fn send(&mut self, byte: u8) -> nb::Result<(), Error> {
-                self.nb_write(byte)
+            fn send(&mut self, word: u8) -> nb::Result<(), Error> {
+                self.nb_write(word)
+            }
+        }
+
+        impl<PINS, TXCH, RXCH> embedded_hal_async::spi::ErrorType for SpiDma<$SPIX, PINS, TXCH, RXCH> {
+            type Error = Error;
+        }
+
+        impl<PINS, TXCH, RXCH, W> embedded_hal_async::spi::SpiBus<W> for SpiDma<$SPIX, PINS, TXCH, RXCH>
+        where
+            W: FrameSize,
+            TXCH: SpiTxDmaChannel,
+            RXCH: SpiRxDmaChannel,
+        {
+            async fn read(&mut self, words: &mut [W]) -> Result<(), Self::Error> {
+                self.async_transfer(&[], words).await
+            }
+
+            async fn write(&mut self, words: &[W]) -> Result<(), Self::Error> {
+                let mut nothing: [W; 0] = [];
+                self.async_transfer(words, &mut nothing).await
+            }
+
+            async fn transfer(&mut self, read: &mut [W], write: &[W]) -> Result<(), Self::Error> {
+                self.async_transfer(write, read).await
+            }
+
+            async fn transfer_in_place(&mut self, words: &mut [W]) -> Result<(), Self::Error> {
+                for i in 0..words.len() {
+                    let w = words[i];
+                    let one = core::slice::from_ref(&w);
+                    self.async_transfer(one, core::slice::from_mut(&mut words[i]))
+                        .await?;
+                }
+                Ok(())
+            }
+
+            async fn flush(&mut self) -> Result<(), Self::Error> {
+                // BSY is expected to clear within a few clock cycles of the
+                // last DMA-driven byte landing, so spin rather than pay for
+                // a Pending/self-wake round trip through the executor.
+                while self.spi.spi.sr.read().bsy().bit_is_set() {
+                    core::hint::spin_loop();
+                }
+                Ok(())
             }
         }
+
+        impl<PINS, TXCH, RXCH> SpiDma<$SPIX, PINS, TXCH, RXCH>
+        where
+            TXCH: SpiTxDmaChannel,
+            RXCH: SpiRxDmaChannel,
+        {
+            /// Move `write` (or `0` once it is exhausted) out to MOSI while
+            /// filling `read` (draining and discarding once `read` is
+            /// exhausted) from MISO, chunking through on-stack scratch
+            /// buffers so the two real DMA channels always see matching
+            /// lengths even when `write`/`read` differ.
+            async fn async_transfer<W: FrameSize>(
+                &mut self,
+                write: &[W],
+                read: &mut [W],
+            ) -> Result<(), Error> {
+                const CHUNK: usize = 32;
+
+                let len = core::cmp::max(write.len(), read.len());
+                if len == 0 {
+                    return Ok(());
+                }
+                self.spi.set_frame_size::<W>();
+
+                let tx_addr =
+                    <Spi<$SPIX, PINS> as TargetAddress<MemoryToPeripheral>>::address(&self.spi);
+                let rx_addr =
+                    <Spi<$SPIX, PINS> as TargetAddress<PeripheralToMemory>>::address(&self.spi);
+
+                let mut offset = 0;
+                while offset < len {
+                    let n = core::cmp::min(CHUNK, len - offset);
+
+                    let mut tx_chunk = [W::default(); CHUNK];
+                    for i in 0..n {
+                        if let Some(w) = write.get(offset + i) {
+                            tx_chunk[i] = *w;
+                        }
+                    }
+                    let mut rx_chunk = [W::default(); CHUNK];
+
+                    // RX is armed first (see `join_transfers`) so the
+                    // receive channel is ready before TX starts toggling
+                    // the clock.
+                    join_transfers(
+                        self.rx_channel.transfer(rx_addr, &mut rx_chunk[..n]),
+                        self.tx_channel.transfer(tx_addr, &tx_chunk[..n]),
+                    )
+                    .await;
+
+                    for i in 0..n {
+                        if let Some(r) = read.get_mut(offset + i) {
+                            *r = rx_chunk[i];
+                        }
+                    }
+
+                    let sr = self.spi.spi.sr.read();
+                    if sr.ovr().bit_is_set() {
+                        return Err(Error::Overrun);
+                    }
+                    if sr.modf().bit_is_set() {
+                        return Err(Error::ModeFault);
+                    }
+
+                    offset += n;
+                }
+                Ok(())
+            }
+        }
+
         unsafe impl<Pin> TargetAddress<MemoryToPeripheral> for Spi<$SPIX, Pin> {
             #[inline(always)]
             fn address(&self) -> u32 {
@@ -423,7 +1086,19 @@ macro_rules! spi {
 
             type MemSize = u8;
 
-            const REQUEST_LINE: Option<u8> = Some($mux as u8);
+            const REQUEST_LINE: Option<u8> = Some($mux_tx as u8);
+        }
+
+        unsafe impl<Pin> TargetAddress<PeripheralToMemory> for Spi<$SPIX, Pin> {
+            #[inline(always)]
+            fn address(&self) -> u32 {
+                // unsafe: only the Rx part accesses the Rx register
+                &unsafe { &*<$SPIX>::ptr() }.dr as *const _ as u32
+            }
+
+            type MemSize = u8;
+
+            const REQUEST_LINE: Option<u8> = Some($mux_rx as u8);
         }
 
 
@@ -472,7 +1147,12 @@ spi!(
         ))]
         PG4<Alternate<AF5>>,
     ],
+    nss: [
+        PA4<Alternate<AF5>>,
+        PA15<Alternate<AF5>>,
+    ],
     DmaMuxResources::SPI1_TX,
+    DmaMuxResources::SPI1_RX,
 );
 
 spi!(
@@ -492,7 +1172,12 @@ spi!(
         PA11<Alternate<AF5>>,
         PB15<Alternate<AF5>>,
     ],
+    nss: [
+        PB9<Alternate<AF5>>,
+        PB12<Alternate<AF5>>,
+    ],
     DmaMuxResources::SPI2_TX,
+    DmaMuxResources::SPI2_RX,
 );
 
 spi!(
@@ -518,7 +1203,12 @@ spi!(
         PB5<Alternate<AF6>>,
         PC12<Alternate<AF6>>,
     ],
+    nss: [
+        PA4<Alternate<AF6>>,
+        PA15<Alternate<AF6>>,
+    ],
     DmaMuxResources::SPI3_TX,
+    DmaMuxResources::SPI3_RX,
 );
 
 #[cfg(any(
@@ -543,5 +1233,10 @@ spi!(
         PE6<Alternate<AF5>>,
         PE14<Alternate<AF5>>,
     ],
+    nss: [
+        PE4<Alternate<AF5>>,
+        PE11<Alternate<AF5>>,
+    ],
     DmaMuxResources::SPI4_TX,
+    DmaMuxResources::SPI4_RX,
 );